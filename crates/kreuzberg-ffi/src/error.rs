@@ -69,23 +69,32 @@
 //!
 //! # Thread Safety
 //!
-//! All functions are thread-safe and have no runtime overhead (compile-time constants).
+//! All functions are thread-safe. Core error codes are compile-time constants
+//! with no runtime overhead; functions that also need to resolve
+//! plugin-registered codes (`ErrorCode::is_valid`, `ErrorCode::name_for`,
+//! `ErrorCode::description_for`, and the `kreuzberg_error_code_name`/
+//! `_description` FFI exports) answer core codes with the constant-time core
+//! lookup and take an `RwLock` read guard over the plugin registry only for
+//! codes outside the core range.
 
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
-
-#[cfg(test)]
-use std::ffi::CStr;
+use std::ptr;
+use std::sync::{OnceLock, RwLock};
 
 /// Centralized error codes for all Kreuzberg bindings.
 ///
 /// These codes are the single source of truth for error classification across
-/// all language bindings. Do not introduce new error codes without updating
-/// this enum and regenerating bindings.
+/// all language bindings. Do not introduce new *core* error codes without
+/// updating this enum and regenerating bindings; a plugin with a
+/// domain-specific failure should instead claim a code in the
+/// [`PLUGIN_ERROR_CODE_MIN`]-and-up range via [`register_plugin_error_code`].
 ///
 /// # Repr and Stability
 ///
 /// - Uses `#[repr(u32)]` for C ABI compatibility
-/// - Error codes are guaranteed stable (0-7, never changing)
+/// - Core error codes are guaranteed stable (0-7, never changing)
 /// - Can be safely cast to `int32_t` in C/C++ code
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -151,6 +160,42 @@ impl ErrorCode {
         }
     }
 
+    /// Returns [`ErrorCode::name`] as a `&'static CStr`.
+    ///
+    /// Unlike a plain `&'static str`, a `CStr` is guaranteed to be
+    /// null-terminated, so its `as_ptr()` is safe to hand across the FFI
+    /// boundary as-is. Used by accessors that need to expose a core code's
+    /// name as a C string (`kreuzberg_error_code_name`).
+    #[inline]
+    pub fn name_cstr(self) -> &'static CStr {
+        match self {
+            ErrorCode::Validation => c"validation",
+            ErrorCode::Parsing => c"parsing",
+            ErrorCode::Ocr => c"ocr",
+            ErrorCode::MissingDependency => c"missing_dependency",
+            ErrorCode::Io => c"io",
+            ErrorCode::Plugin => c"plugin",
+            ErrorCode::UnsupportedFormat => c"unsupported_format",
+            ErrorCode::Internal => c"internal",
+        }
+    }
+
+    /// Returns [`ErrorCode::description`] as a `&'static CStr`. See
+    /// [`ErrorCode::name_cstr`] for why this exists alongside `description`.
+    #[inline]
+    pub fn description_cstr(self) -> &'static CStr {
+        match self {
+            ErrorCode::Validation => c"Input validation error",
+            ErrorCode::Parsing => c"Document parsing error",
+            ErrorCode::Ocr => c"OCR processing error",
+            ErrorCode::MissingDependency => c"Missing system dependency",
+            ErrorCode::Io => c"File system I/O error",
+            ErrorCode::Plugin => c"Plugin error",
+            ErrorCode::UnsupportedFormat => c"Unsupported format",
+            ErrorCode::Internal => c"Internal library error",
+        }
+    }
+
     /// Converts from numeric error code to enum variant.
     ///
     /// Returns `None` if the code is outside the valid range [0, 7].
@@ -177,7 +222,9 @@ impl ErrorCode {
         }
     }
 
-    /// Checks if a numeric code is valid (within [0, 7]).
+    /// Checks if a numeric code is valid: either one of the core codes
+    /// `[0, 7]`, or a code registered by a plugin via
+    /// [`register_plugin_error_code`].
     ///
     /// # Examples
     ///
@@ -188,8 +235,145 @@ impl ErrorCode {
     /// ```
     #[inline]
     pub fn is_valid(code: u32) -> bool {
-        code <= 7
+        code <= 7 || plugin_error_codes().read().unwrap().contains_key(&code)
     }
+
+    /// Returns the name for any valid code, core or plugin-registered.
+    ///
+    /// This is the `u32`-based counterpart to [`ErrorCode::name`]: plugin
+    /// codes live outside the fixed `ErrorCode` enum (see
+    /// [`PLUGIN_ERROR_CODE_MIN`]), so they can only be looked up by raw code.
+    /// Returns `None` if `code` is neither a core code nor registered.
+    ///
+    /// Unlike [`ErrorCode::name`], the string this returns is backed by a
+    /// real NUL terminator (see [`ErrorCode::name_cstr`]), since this is the
+    /// `u32`-based lookup the FFI name/description accessors use.
+    pub fn name_for(code: u32) -> Option<&'static str> {
+        if let Some(core) = ErrorCode::from_code(code) {
+            // `to_str()` on a `&'static CStr` yields a `&'static str` over
+            // the same bytes, so the real NUL terminator is still right
+            // there in memory, unlike a plain `&str` literal's `as_ptr()`.
+            return Some(core.name_cstr().to_str().expect("name_cstr() is always valid UTF-8"));
+        }
+        plugin_error_codes().read().unwrap().get(&code).map(|entry| entry.name)
+    }
+
+    /// Returns the description for any valid code, core or
+    /// plugin-registered. See [`ErrorCode::name_for`] for why this takes a
+    /// raw `u32` instead of being an instance method, and why the result is
+    /// NUL-terminated unlike [`ErrorCode::description`].
+    pub fn description_for(code: u32) -> Option<&'static str> {
+        if let Some(core) = ErrorCode::from_code(code) {
+            return Some(
+                core.description_cstr()
+                    .to_str()
+                    .expect("description_cstr() is always valid UTF-8"),
+            );
+        }
+        plugin_error_codes()
+            .read()
+            .unwrap()
+            .get(&code)
+            .map(|entry| entry.description)
+    }
+}
+
+/// Start of the error-code range reserved for plugins (see
+/// [`register_plugin_error_code`]). Core codes `[0, 7]` are compile-time
+/// constants and can never collide with this range.
+pub const PLUGIN_ERROR_CODE_MIN: u32 = 1000;
+
+/// A plugin-registered error code: a stable sub-code in the
+/// [`PLUGIN_ERROR_CODE_MIN`]-and-up range, with its own name and
+/// description, analogous to the core codes' `name()`/`description()`.
+struct PluginErrorCodeEntry {
+    name: &'static str,
+    description: &'static str,
+}
+
+/// Runtime registry of plugin-defined error codes, keyed by their numeric
+/// code. Populated via [`register_plugin_error_code`] / the FFI entry point
+/// `kreuzberg_register_error_code`, and consulted by [`ErrorCode::is_valid`],
+/// [`ErrorCode::name_for`], and [`ErrorCode::description_for`].
+///
+/// Entries, once registered, live for the remainder of the process: their
+/// `name`/`description` strings are leaked to `'static` so that
+/// `kreuzberg_error_code_name`/`_description` can keep returning
+/// non-owned, never-freed pointers for plugin codes exactly as they already
+/// do for core codes. Registration is expected to happen a handful of times
+/// at plugin load, not on any hot path.
+fn plugin_error_codes() -> &'static RwLock<HashMap<u32, PluginErrorCodeEntry>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<u32, PluginErrorCodeEntry>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a plugin-defined error code in the
+/// [`PLUGIN_ERROR_CODE_MIN`]-and-up reserved range.
+///
+/// Returns `true` on success. Returns `false`, and registers nothing, if
+/// `code < PLUGIN_ERROR_CODE_MIN` (the core range is immutable), either
+/// `name` or `description` is empty, or either contains an interior NUL byte
+/// (which would make it unrepresentable as a C string). Re-registering an
+/// existing code overwrites its name/description.
+pub fn register_plugin_error_code(code: u32, name: &str, description: &str) -> bool {
+    if code < PLUGIN_ERROR_CODE_MIN || name.is_empty() || description.is_empty() {
+        return false;
+    }
+    let (Some(name), Some(description)) = (leak_nul_terminated(name), leak_nul_terminated(description)) else {
+        return false;
+    };
+    plugin_error_codes()
+        .write()
+        .unwrap()
+        .insert(code, PluginErrorCodeEntry { name, description });
+    true
+}
+
+/// Leaks `s` as a `&'static str` backed by a null-terminated allocation, so
+/// that `as_ptr()` on the result is safe to hand to C callers as-is (unlike a
+/// plain `&str`, which carries no terminator of its own). Returns `None` if
+/// `s` contains an interior NUL byte and so cannot be represented as a C
+/// string.
+fn leak_nul_terminated(s: &str) -> Option<&'static str> {
+    let bytes_with_nul: &'static [u8] = Box::leak(CString::new(s).ok()?.into_bytes_with_nul().into_boxed_slice());
+    // The slice excludes the trailing NUL, but it is still right there in the
+    // same allocation, so a C caller reading past the end of this str finds it.
+    Some(std::str::from_utf8(&bytes_with_nul[..bytes_with_nul.len() - 1]).expect("CString content is valid UTF-8"))
+}
+
+/// Registers a plugin-defined error code from C bindings. See
+/// [`register_plugin_error_code`].
+///
+/// Returns `false` without registering anything if `code` is outside the
+/// reserved range ([`PLUGIN_ERROR_CODE_MIN`] and up), or if `name` or
+/// `description` is null or not valid UTF-8.
+///
+/// # Safety
+///
+/// `name` and `description` must each be null or point to a valid,
+/// null-terminated C string.
+///
+/// # C Signature
+///
+/// ```c
+/// bool kreuzberg_register_error_code(uint32_t code, const char* name, const char* description);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_register_error_code(
+    code: u32,
+    name: *const c_char,
+    description: *const c_char,
+) -> bool {
+    if name.is_null() || description.is_null() {
+        return false;
+    }
+    // SAFETY: caller guarantees `name` and `description`, just checked
+    // non-null, point to valid null-terminated C strings.
+    let (name, description) = unsafe { (CStr::from_ptr(name), CStr::from_ptr(description)) };
+    let (Ok(name), Ok(description)) = (name.to_str(), description.to_str()) else {
+        return false;
+    };
+    register_plugin_error_code(code, name, description)
 }
 
 // FFI exports - these functions provide C-compatible access to error codes.
@@ -332,17 +516,14 @@ pub extern "C" fn kreuzberg_error_code_count() -> u32 {
 /// ```
 #[unsafe(no_mangle)]
 pub extern "C" fn kreuzberg_error_code_name(code: u32) -> *const c_char {
-    match ErrorCode::from_code(code) {
-        Some(err_code) => {
-            let name = err_code.name();
-            // SAFETY: name() returns &'static str from a match statement on valid variants.
+    match ErrorCode::name_for(code) {
+        Some(name) => {
+            // SAFETY: name_for() returns &'static str, either a match arm on a
+            // valid core variant or a leaked, never-freed plugin registration.
             // All static strings are guaranteed to be valid C strings (null-terminated).
             name.as_ptr() as *const c_char
         }
-        None => {
-            // SAFETY: "unknown" is a string literal and is valid for the lifetime of the program.
-            "unknown".as_ptr() as *const c_char
-        }
+        None => c"unknown".as_ptr(),
     }
 }
 
@@ -366,17 +547,353 @@ pub extern "C" fn kreuzberg_error_code_name(code: u32) -> *const c_char {
 /// ```
 #[unsafe(no_mangle)]
 pub extern "C" fn kreuzberg_error_code_description(code: u32) -> *const c_char {
-    match ErrorCode::from_code(code) {
-        Some(err_code) => {
-            let desc = err_code.description();
-            // SAFETY: description() returns &'static str. Same reasoning as name().
+    match ErrorCode::description_for(code) {
+        Some(desc) => {
+            // SAFETY: description_for() returns &'static str. Same reasoning as name().
             desc.as_ptr() as *const c_char
         }
-        None => {
-            // SAFETY: string literal, valid for program lifetime
-            "Unknown error code".as_ptr() as *const c_char
+        None => c"Unknown error code".as_ptr(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Structured errors
+// ---------------------------------------------------------------------------
+
+/// Well-known context keys attached to [`KreuzbergError`] instances.
+///
+/// Bindings should treat these as stable identifiers when calling
+/// `kreuzberg_error_get_context`. Not every error carries every key; only the
+/// keys relevant to its [`ErrorCode`] are populated.
+pub mod context_keys {
+    /// The file path that triggered an `UnsupportedFormat` or `Parsing` error.
+    pub const PATH: &str = "path";
+    /// The detected MIME type for an `UnsupportedFormat` or `Parsing` error.
+    pub const MIME_TYPE: &str = "mime_type";
+    /// The missing binary name for a `MissingDependency` error.
+    pub const BINARY_NAME: &str = "binary_name";
+    /// The OCR backend identifier for an `Ocr` error.
+    pub const BACKEND_ID: &str = "backend_id";
+    /// The plugin name for a `Plugin` error.
+    pub const PLUGIN_NAME: &str = "plugin_name";
+}
+
+/// The allocated payload backing a [`KreuzbergError`] that carries a message
+/// and/or context fields.
+///
+/// Kept separate from [`KreuzbergError`] so the common "just a code" case
+/// never has to allocate one of these.
+struct Custom {
+    code: ErrorCode,
+    message: CString,
+    context: Vec<(&'static str, CString)>,
+}
+
+/// A bare `ErrorCode` is tagged with 0, a pointer to a boxed [`Custom`] is
+/// tagged with 1. `Custom` is heap-allocated, so its address is at least
+/// word-aligned, which leaves the low bit free for the tag.
+const TAG_CUSTOM: usize = 0b1;
+const TAG_MASK: usize = 0b1;
+
+enum ReprUnpacked<'a> {
+    Bare(ErrorCode),
+    Custom(&'a Custom),
+}
+
+/// An opaque, FFI-safe error object that bundles an [`ErrorCode`] with an
+/// owned message and a small set of typed context fields (e.g. the offending
+/// file path, the missing binary name).
+///
+/// # Representation
+///
+/// Mirrors the packing trick behind std's `io::Error`: a [`KreuzbergError`]
+/// is a single tagged `usize`. When no message or context is attached, the
+/// bare `ErrorCode` is packed directly into that word and construction is a
+/// single shift with no allocation. Only errors that need a message or
+/// context pay for a heap allocation (a boxed [`Custom`]).
+///
+/// Instances are created and owned by the Rust side. Bindings receive a
+/// `*mut KreuzbergError` from a fallible call and must release it with
+/// `kreuzberg_error_free` once they are done reading it.
+#[repr(transparent)]
+pub struct KreuzbergError {
+    data: usize,
+}
+
+impl KreuzbergError {
+    /// Creates a bare error with just a code, no allocation.
+    #[inline]
+    pub fn new(code: ErrorCode) -> Self {
+        KreuzbergError {
+            data: (code as usize) << 1,
+        }
+    }
+
+    /// Creates an error with a code and a heap-allocated message.
+    pub fn with_message(code: ErrorCode, message: impl Into<Vec<u8>>) -> Self {
+        Self::from_custom(Custom {
+            code,
+            message: CString::new(message).unwrap_or_else(|_| CString::new("<invalid message>").unwrap()),
+            context: Vec::new(),
+        })
+    }
+
+    /// Attaches a typed context field (see [`context_keys`]), allocating a
+    /// [`Custom`] payload if this error was previously bare.
+    pub fn with_context(self, key: &'static str, value: impl Into<Vec<u8>>) -> Self {
+        let value = CString::new(value).unwrap_or_else(|_| CString::new("<invalid value>").unwrap());
+        let mut custom = match self.unpack_owned() {
+            ReprOwned::Bare(code) => Custom {
+                code,
+                // Seed with the code's default description, matching what
+                // `message()` would report on the still-bare representation,
+                // so promoting to `Custom` via `with_context` alone doesn't
+                // silently swap that fallback for an empty message.
+                message: code.description_cstr().to_owned(),
+                context: Vec::new(),
+            },
+            ReprOwned::Custom(custom) => *custom,
+        };
+        custom.context.retain(|(k, _)| *k != key);
+        custom.context.push((key, value));
+        Self::from_custom(custom)
+    }
+
+    /// Convenience constructor for an `UnsupportedFormat` error.
+    pub fn unsupported_format(path: impl Into<Vec<u8>>, mime_type: impl Into<Vec<u8>>) -> Self {
+        Self::with_message(ErrorCode::UnsupportedFormat, "unsupported format")
+            .with_context(context_keys::PATH, path)
+            .with_context(context_keys::MIME_TYPE, mime_type)
+    }
+
+    /// Convenience constructor for a `MissingDependency` error.
+    pub fn missing_dependency(binary_name: impl Into<Vec<u8>>) -> Self {
+        Self::with_message(ErrorCode::MissingDependency, "missing system dependency")
+            .with_context(context_keys::BINARY_NAME, binary_name)
+    }
+
+    /// Convenience constructor for an `Ocr` error.
+    pub fn ocr(backend_id: impl Into<Vec<u8>>, message: impl Into<Vec<u8>>) -> Self {
+        Self::with_message(ErrorCode::Ocr, message).with_context(context_keys::BACKEND_ID, backend_id)
+    }
+
+    /// Convenience constructor for a `Plugin` error.
+    pub fn plugin(plugin_name: impl Into<Vec<u8>>, message: impl Into<Vec<u8>>) -> Self {
+        Self::with_message(ErrorCode::Plugin, message).with_context(context_keys::PLUGIN_NAME, plugin_name)
+    }
+
+    fn from_custom(custom: Custom) -> Self {
+        let ptr = Box::into_raw(Box::new(custom)) as usize;
+        debug_assert_eq!(ptr & TAG_MASK, 0, "Box<Custom> must be word-aligned");
+        KreuzbergError {
+            data: ptr | TAG_CUSTOM,
+        }
+    }
+
+    fn unpack(&self) -> ReprUnpacked<'_> {
+        if self.data & TAG_MASK == TAG_CUSTOM {
+            // SAFETY: this bit pattern is only ever produced by `from_custom`,
+            // which packs a `Box::into_raw(Box<Custom>)` pointer with this tag.
+            let custom = unsafe { &*((self.data & !TAG_MASK) as *const Custom) };
+            ReprUnpacked::Custom(custom)
+        } else {
+            let code = (self.data >> 1) as u32;
+            // SAFETY: the only way to produce a bare repr is `new`, which
+            // shifts in a valid `ErrorCode` discriminant.
+            ReprUnpacked::Bare(ErrorCode::from_code(code).expect("bare repr always holds a valid ErrorCode"))
+        }
+    }
+
+    /// Consumes `self` and returns an owned view, taking back ownership of
+    /// any boxed `Custom` so it can be mutated without a second allocation.
+    fn unpack_owned(self) -> ReprOwned {
+        if self.data & TAG_MASK == TAG_CUSTOM {
+            // SAFETY: see `unpack`; we own `self` so it is sound to reclaim
+            // the box and skip running `Drop` on the bit pattern below.
+            let custom = unsafe { Box::from_raw((self.data & !TAG_MASK) as *mut Custom) };
+            std::mem::forget(self);
+            ReprOwned::Custom(custom)
+        } else {
+            let code = (self.data >> 1) as u32;
+            ReprOwned::Bare(ErrorCode::from_code(code).expect("bare repr always holds a valid ErrorCode"))
         }
     }
+
+    /// Returns this error's [`ErrorCode`].
+    pub fn code(&self) -> ErrorCode {
+        match self.unpack() {
+            ReprUnpacked::Bare(code) => code,
+            ReprUnpacked::Custom(custom) => custom.code,
+        }
+    }
+
+    /// Returns this error's message, or its code's default description if
+    /// none was attached.
+    pub fn message(&self) -> &str {
+        match self.unpack() {
+            ReprUnpacked::Bare(code) => code.description(),
+            ReprUnpacked::Custom(custom) => custom.message.to_str().unwrap_or("<invalid utf8 message>"),
+        }
+    }
+
+    /// Returns the value of a context field by key, if present.
+    pub fn context(&self, key: &str) -> Option<&str> {
+        match self.unpack() {
+            ReprUnpacked::Bare(_) => None,
+            ReprUnpacked::Custom(custom) => custom
+                .context
+                .iter()
+                .find(|(k, _)| *k == key)
+                .and_then(|(_, v)| v.to_str().ok()),
+        }
+    }
+}
+
+impl Drop for KreuzbergError {
+    fn drop(&mut self) {
+        if self.data & TAG_MASK == TAG_CUSTOM {
+            // SAFETY: see `unpack`; dropping the reclaimed box frees the
+            // allocation exactly once since `data` is never duplicated.
+            unsafe {
+                drop(Box::from_raw((self.data & !TAG_MASK) as *mut Custom));
+            }
+        }
+    }
+}
+
+enum ReprOwned {
+    Bare(ErrorCode),
+    Custom(Box<Custom>),
+}
+
+/// Returns this error's numeric [`ErrorCode`] as a `u32`.
+///
+/// Returns `ErrorCode::Internal as u32` if `err` is null.
+///
+/// # Safety
+///
+/// `err` must be null or point to a live `KreuzbergError` produced by this
+/// library and not yet passed to `kreuzberg_error_free`.
+///
+/// # C Signature
+///
+/// ```c
+/// uint32_t kreuzberg_error_get_code(const KreuzbergError* err);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_error_get_code(err: *const KreuzbergError) -> u32 {
+    // SAFETY: caller guarantees `err`, if non-null, points to a live
+    // `KreuzbergError` produced by this library.
+    match unsafe { err.as_ref() } {
+        Some(err) => err.code() as u32,
+        None => ErrorCode::Internal as u32,
+    }
+}
+
+/// Returns this error's message as a borrowed, null-terminated C string.
+///
+/// The returned pointer is valid only until `err` is freed with
+/// `kreuzberg_error_free` and must not be freed separately by the caller.
+/// Returns null if `err` is null.
+///
+/// # Safety
+///
+/// `err` must be null or point to a live `KreuzbergError` produced by this
+/// library and not yet passed to `kreuzberg_error_free`.
+///
+/// # C Signature
+///
+/// ```c
+/// const char* kreuzberg_error_get_message(const KreuzbergError* err);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_error_get_message(err: *const KreuzbergError) -> *const c_char {
+    // SAFETY: caller guarantees `err`, if non-null, points to a live
+    // `KreuzbergError` produced by this library.
+    match unsafe { err.as_ref() } {
+        Some(err) => match err.unpack() {
+            // `description_cstr()` is a real null-terminated static, unlike
+            // `description().as_ptr()`, which would read past the end of a
+            // plain `&str` looking for a terminator that isn't there.
+            ReprUnpacked::Bare(code) => code.description_cstr().as_ptr(),
+            ReprUnpacked::Custom(custom) => custom.message.as_ptr(),
+        },
+        None => ptr::null(),
+    }
+}
+
+/// Returns the value of a context field (see [`context_keys`]) as a
+/// borrowed, null-terminated C string, or null if `err`, `key` is null, `key`
+/// is not valid UTF-8, or no such field is attached.
+///
+/// The returned pointer is valid only until `err` is freed with
+/// `kreuzberg_error_free`.
+///
+/// # Safety
+///
+/// `err` must be null or point to a live `KreuzbergError` produced by this
+/// library and not yet passed to `kreuzberg_error_free`. `key` must be null
+/// or point to a valid, null-terminated C string.
+///
+/// # C Signature
+///
+/// ```c
+/// const char* kreuzberg_error_get_context(const KreuzbergError* err, const char* key);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_error_get_context(
+    err: *const KreuzbergError,
+    key: *const c_char,
+) -> *const c_char {
+    if key.is_null() {
+        return ptr::null();
+    }
+    // SAFETY: caller guarantees `err`, if non-null, points to a live
+    // `KreuzbergError`, and `key`, just checked non-null, points to a valid
+    // null-terminated C string.
+    let (err, key) = unsafe { (err.as_ref(), CStr::from_ptr(key)) };
+    let Some(err) = err else {
+        return ptr::null();
+    };
+    let Ok(key) = key.to_str() else {
+        return ptr::null();
+    };
+    match err.unpack() {
+        ReprUnpacked::Bare(_) => ptr::null(),
+        ReprUnpacked::Custom(custom) => custom
+            .context
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_ptr())
+            .unwrap_or(ptr::null()),
+    }
+}
+
+/// Frees a [`KreuzbergError`] previously returned to a binding.
+///
+/// Passing null is a no-op. Double-freeing the same pointer is undefined
+/// behavior, as with any owned FFI handle.
+///
+/// # Safety
+///
+/// `err` must be null or a pointer previously returned by this library and
+/// not yet passed to `kreuzberg_error_free`.
+///
+/// # C Signature
+///
+/// ```c
+/// void kreuzberg_error_free(KreuzbergError* err);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_error_free(err: *mut KreuzbergError) {
+    if err.is_null() {
+        return;
+    }
+    // SAFETY: caller guarantees `err` is either null (handled above) or a
+    // pointer previously returned by this library and not yet freed.
+    unsafe {
+        drop(Box::from_raw(err));
+    }
 }
 
 #[cfg(test)]
@@ -538,4 +1055,183 @@ mod tests {
         let debug_str = format!("{:?}", err);
         assert!(debug_str.contains("Ocr"));
     }
+
+    #[test]
+    fn test_kreuzberg_error_bare_has_no_message_allocation() {
+        let err = KreuzbergError::new(ErrorCode::Internal);
+        assert_eq!(err.code(), ErrorCode::Internal);
+        assert_eq!(err.message(), ErrorCode::Internal.description());
+        assert_eq!(err.context("anything"), None);
+    }
+
+    #[test]
+    fn test_kreuzberg_error_with_context_alone_keeps_default_message() {
+        let err = KreuzbergError::new(ErrorCode::Ocr).with_context(context_keys::BACKEND_ID, "x");
+        assert_eq!(err.message(), ErrorCode::Ocr.description());
+        assert_eq!(err.context(context_keys::BACKEND_ID), Some("x"));
+    }
+
+    #[test]
+    fn test_kreuzberg_error_get_message_ffi_on_bare_error() {
+        let err = Box::into_raw(Box::new(KreuzbergError::new(ErrorCode::Internal)));
+
+        // SAFETY: test only, `err` is valid and non-null.
+        unsafe {
+            let message = CStr::from_ptr(kreuzberg_error_get_message(err)).to_str().unwrap();
+            assert_eq!(message, ErrorCode::Internal.description());
+
+            kreuzberg_error_free(err);
+        }
+    }
+
+    #[test]
+    fn test_kreuzberg_error_with_message() {
+        let err = KreuzbergError::with_message(ErrorCode::Parsing, "could not parse document");
+        assert_eq!(err.code(), ErrorCode::Parsing);
+        assert_eq!(err.message(), "could not parse document");
+    }
+
+    #[test]
+    fn test_kreuzberg_error_unsupported_format_context() {
+        let err = KreuzbergError::unsupported_format("/tmp/report.xyz", "application/x-unknown");
+        assert_eq!(err.code(), ErrorCode::UnsupportedFormat);
+        assert_eq!(err.context(context_keys::PATH), Some("/tmp/report.xyz"));
+        assert_eq!(err.context(context_keys::MIME_TYPE), Some("application/x-unknown"));
+        assert_eq!(err.context(context_keys::BINARY_NAME), None);
+    }
+
+    #[test]
+    fn test_kreuzberg_error_missing_dependency_context() {
+        let err = KreuzbergError::missing_dependency("tesseract");
+        assert_eq!(err.code(), ErrorCode::MissingDependency);
+        assert_eq!(err.context(context_keys::BINARY_NAME), Some("tesseract"));
+    }
+
+    #[test]
+    fn test_kreuzberg_error_ocr_context() {
+        let err = KreuzbergError::ocr("tesseract-5", "engine crashed");
+        assert_eq!(err.code(), ErrorCode::Ocr);
+        assert_eq!(err.message(), "engine crashed");
+        assert_eq!(err.context(context_keys::BACKEND_ID), Some("tesseract-5"));
+    }
+
+    #[test]
+    fn test_kreuzberg_error_plugin_context() {
+        let err = KreuzbergError::plugin("my-plugin", "failed to load config");
+        assert_eq!(err.code(), ErrorCode::Plugin);
+        assert_eq!(err.context(context_keys::PLUGIN_NAME), Some("my-plugin"));
+    }
+
+    #[test]
+    fn test_kreuzberg_error_with_context_replaces_existing_key() {
+        let err = KreuzbergError::with_message(ErrorCode::Parsing, "oops")
+            .with_context(context_keys::PATH, "/a")
+            .with_context(context_keys::PATH, "/b");
+        assert_eq!(err.context(context_keys::PATH), Some("/b"));
+    }
+
+    #[test]
+    fn test_kreuzberg_error_ffi_round_trip() {
+        let err = Box::into_raw(Box::new(KreuzbergError::unsupported_format("/tmp/x.docx", "text/plain")));
+
+        // SAFETY: test only, pointers are valid and non-null.
+        unsafe {
+            assert_eq!(kreuzberg_error_get_code(err), ErrorCode::UnsupportedFormat as u32);
+
+            let message = CStr::from_ptr(kreuzberg_error_get_message(err)).to_str().unwrap();
+            assert!(!message.is_empty());
+
+            let path_key = CString::new(context_keys::PATH).unwrap();
+            let path = CStr::from_ptr(kreuzberg_error_get_context(err, path_key.as_ptr()))
+                .to_str()
+                .unwrap();
+            assert_eq!(path, "/tmp/x.docx");
+
+            let missing_key = CString::new("no_such_key").unwrap();
+            assert!(kreuzberg_error_get_context(err, missing_key.as_ptr()).is_null());
+
+            kreuzberg_error_free(err);
+        }
+    }
+
+    #[test]
+    fn test_kreuzberg_error_ffi_null_handling() {
+        // SAFETY: test only, all pointers are explicitly null.
+        unsafe {
+            assert_eq!(kreuzberg_error_get_code(ptr::null()), ErrorCode::Internal as u32);
+            assert!(kreuzberg_error_get_message(ptr::null()).is_null());
+            assert!(kreuzberg_error_get_context(ptr::null(), ptr::null()).is_null());
+            kreuzberg_error_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_register_plugin_error_code_rejects_below_plugin_range() {
+        assert!(!register_plugin_error_code(7, "not_a_plugin_code", "rejected"));
+        assert!(!register_plugin_error_code(999, "below_plugin_range", "rejected"));
+    }
+
+    #[test]
+    fn test_register_plugin_error_code_rejects_empty_strings() {
+        assert!(!register_plugin_error_code(1001, "", "description"));
+        assert!(!register_plugin_error_code(1002, "name", ""));
+    }
+
+    #[test]
+    fn test_register_plugin_error_code_rejects_interior_nul() {
+        assert!(!register_plugin_error_code(1003, "bad\0name", "description"));
+        assert!(!register_plugin_error_code(1004, "name", "bad\0description"));
+        assert_eq!(ErrorCode::name_for(1003), None);
+        assert_eq!(ErrorCode::name_for(1004), None);
+    }
+
+    #[test]
+    fn test_register_plugin_error_code_round_trip() {
+        assert!(register_plugin_error_code(1010, "csv_dialect_detection_failed", "Could not detect CSV dialect"));
+
+        assert!(ErrorCode::is_valid(1010));
+        assert_eq!(ErrorCode::name_for(1010), Some("csv_dialect_detection_failed"));
+        assert_eq!(ErrorCode::description_for(1010), Some("Could not detect CSV dialect"));
+
+        // Core codes are untouched.
+        assert_eq!(ErrorCode::name_for(5), Some(ErrorCode::Plugin.name()));
+    }
+
+    #[test]
+    fn test_register_plugin_error_code_overwrite() {
+        assert!(register_plugin_error_code(1020, "first_name", "first description"));
+        assert!(register_plugin_error_code(1020, "second_name", "second description"));
+
+        assert_eq!(ErrorCode::name_for(1020), Some("second_name"));
+        assert_eq!(ErrorCode::description_for(1020), Some("second description"));
+    }
+
+    #[test]
+    fn test_unregistered_high_code_is_invalid() {
+        assert!(!ErrorCode::is_valid(1999));
+        assert_eq!(ErrorCode::name_for(1999), None);
+        assert_eq!(ErrorCode::description_for(1999), None);
+    }
+
+    #[test]
+    fn test_kreuzberg_register_error_code_ffi() {
+        let name = CString::new("ocr_model_unavailable").unwrap();
+        let description = CString::new("The requested OCR model is not installed").unwrap();
+
+        // SAFETY: test only, pointers are valid, null, or explicitly null below.
+        unsafe {
+            assert!(kreuzberg_register_error_code(1030, name.as_ptr(), description.as_ptr()));
+            assert!(!kreuzberg_register_error_code(1031, ptr::null(), description.as_ptr()));
+            assert!(!kreuzberg_register_error_code(1032, name.as_ptr(), ptr::null()));
+        }
+
+        // SAFETY: test only, pointer is valid and non-null.
+        unsafe {
+            let returned_name = CStr::from_ptr(kreuzberg_error_code_name(1030)).to_str().unwrap();
+            assert_eq!(returned_name, "ocr_model_unavailable");
+
+            let returned_desc = CStr::from_ptr(kreuzberg_error_code_description(1030)).to_str().unwrap();
+            assert_eq!(returned_desc, "The requested OCR model is not installed");
+        }
+    }
 }